@@ -1,6 +1,21 @@
-use rayon::prelude::*;
 use std::cmp::Ordering;
 
+/// Flips the sign of `value` when `direction` marks the objective as
+/// minimized, so that a uniform "larger is better" comparison can be reused
+/// for mixed maximize/minimize problems
+///
+/// # Arguments
+///
+/// * `value` - The raw objective value
+/// * `direction` - `1` to maximize the objective, `-1` to minimize it
+fn apply_direction(value: f64, direction: i8) -> f64 {
+    if direction < 0 {
+        -value
+    } else {
+        value
+    }
+}
+
 /// Returns true if a is dominated by b
 /// a is dominated by b if all elements of a are greater than or equal to the corresponding elements of b
 /// and at least one element of a is strictly greater than the corresponding element of b
@@ -10,20 +25,23 @@ use std::cmp::Ordering;
 ///
 /// * `a` - A slice of f64
 /// * `b` - A slice of f64
+/// * `directions` - An optional slice of `1` (maximize) / `-1` (minimize), one
+///   per objective. Defaults to all-maximize when `None`, so existing callers
+///   are unaffected.
 ///
 /// # Example
 ///
 /// ```rust
 /// let a = vec![1.0, 2.0, 3.0];
 /// let b = vec![1.0, 2.0, 4.0];
-/// let result = is_dominated(a.as_slice(), b.as_slice());
+/// let result = is_dominated(a.as_slice(), b.as_slice(), None);
 /// assert_eq!(result, false);
 /// ```
 ///
 /// ```rust
 /// let a = vec![1.0, 2.0, 4.0];
 /// let b = vec![1.0, 2.0, 3.0];
-/// let result = is_dominated(a.as_slice(), b.as_slice());
+/// let result = is_dominated(a.as_slice(), b.as_slice(), None);
 /// assert_eq!(result, true);
 /// ```
 /// # Returns
@@ -47,9 +65,14 @@ use std::cmp::Ordering;
 /// This function is used to sort multi-objective optimization results
 /// according to a pareto-optimal front
 ///
-fn is_dominated(a: &[f64], b: &[f64]) -> bool {
-    let a_ge_b: bool = a.iter().zip(b.iter()).all(|(ai, bi)| ai >= bi);
-    let a_gt_b: bool = a.iter().zip(b.iter()).any(|(ai, bi)| ai > bi);
+fn is_dominated(a: &[f64], b: &[f64], directions: Option<&[i8]>) -> bool {
+    let direction_at = |i: usize| directions.map_or(1, |ds| ds[i]);
+    let a_ge_b: bool = a.iter().zip(b.iter()).enumerate().all(|(i, (ai, bi))| {
+        apply_direction(*ai, direction_at(i)) >= apply_direction(*bi, direction_at(i))
+    });
+    let a_gt_b: bool = a.iter().zip(b.iter()).enumerate().any(|(i, (ai, bi))| {
+        apply_direction(*ai, direction_at(i)) > apply_direction(*bi, direction_at(i))
+    });
     a_ge_b && a_gt_b
 }
 
@@ -63,13 +86,15 @@ fn is_dominated(a: &[f64], b: &[f64]) -> bool {
 ///
 /// * `a` - A slice of f64
 /// * `b` - A slice of f64
+/// * `directions` - An optional slice of `1` (maximize) / `-1` (minimize), one
+///   per objective. Defaults to all-maximize when `None`.
 ///
 /// # Example
 ///
 /// ```rust
 /// let a = vec![1.0, 2.0, 3.0];
 /// let b = vec![1.0, 2.0, 4.0];
-/// let result = domination_order(a.as_slice(), b.as_slice());
+/// let result = domination_order(a.as_slice(), b.as_slice(), None);
 /// println!("{}", result);
 /// ```
 ///
@@ -95,23 +120,124 @@ fn is_dominated(a: &[f64], b: &[f64]) -> bool {
 ///
 /// This function is used to sort multi-objective optimization results
 /// according to a pareto-optimal front
-fn domination_order(a: &[f64], b: &[f64]) -> Ordering {
-    if is_dominated(a, b) {
+fn domination_order(a: &[f64], b: &[f64], directions: Option<&[i8]>) -> Ordering {
+    if is_dominated(a, b, directions) {
         Ordering::Greater
-    } else if is_dominated(b, a) {
+    } else if is_dominated(b, a, directions) {
         Ordering::Less
     } else {
         Ordering::Equal
     }
 }
 
-/// Returns the pareto-optimal ordering of a set of points
+/// A dominance relation over solution types other than bare `&[f64]`
+///
+/// Implementing this for a type lets it be ranked by [`pareto_sort_rs`] and
+/// [`non_dominated_fronts_rs`] without flattening it into a float vector
+/// first, so a structured solution (objectives plus decision variables, say)
+/// keeps its link back to the original candidate through the sort.
+pub trait DominanceOrd {
+    /// Compares `self` to `other`, returning `Greater` if `self` dominates
+    /// `other`, `Less` if `self` is dominated by `other`, and `Equal` if
+    /// neither dominates the other
+    fn dominance_cmp(&self, other: &Self) -> Ordering;
+}
+
+impl DominanceOrd for &[f64] {
+    fn dominance_cmp(&self, other: &Self) -> Ordering {
+        domination_order(self, other, None)
+    }
+}
+
+/// Returns the pareto-optimal ordering of a set of solutions
 /// This is used to sort multi-objective optimization results,
 /// using a pareto-optimal condition
 ///
+/// Generic over any `T: DominanceOrd`, so callers are not limited to bare
+/// `&[f64]` points - see [`DominanceOrd`]. For `&[f64]` points that need
+/// per-objective minimize/maximize directions, use
+/// [`pareto_sort_with_directions_rs`] instead.
+///
+/// # Arguments
+///
+/// * `points` - A slice of solutions to rank
+///
+/// # Example
+///
+/// ```rust
+/// let points = vec![
+///    vec![1.0, 2.0, 3.0],
+///    vec![1.0, 3.0, 4.0],
+///    vec![1.0, 3.0, 5.0]
+/// ];
+/// let refs: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+/// let result = pareto_sort_rs(&refs);
+/// let expected: Vec<&[f64]> = vec![refs[2]];
+/// assert_eq!(result, expected);
+/// ```
+///
+/// # Returns
+///
+/// The non-dominated subset of `points`, sorted according to the
+/// pareto-optimal condition
+///
+/// # Panics
+///
+/// This function will panic if the length of any of the vectors in points are not equal
+///
+/// # Safety
+///
+/// This function is safe to use
+///
+/// # Performance
+///
+/// This function is very fast and can be used in performance critical applications
+///
+pub fn pareto_sort_rs<T: DominanceOrd + Clone>(points: &[T]) -> Vec<T> {
+    let mut pareto_front: Vec<T> = Vec::new();
+    for point in points.iter() {
+        let mut dominated = false;
+        pareto_front.retain(|p| match p.dominance_cmp(point) {
+            Ordering::Greater => {
+                dominated = true;
+                true
+            }
+            Ordering::Less => false,
+            Ordering::Equal => true,
+        });
+        if !dominated {
+            pareto_front.push(point.clone());
+        }
+    }
+    pareto_front
+}
+
+/// Negates the coordinates of `point` for every objective marked as
+/// minimized, so a direction-agnostic dominance check can be reused. Since
+/// negation is its own inverse, applying this twice with the same
+/// `directions` recovers the original point.
+fn transform_for_directions(point: &[f64], directions: &[i8]) -> Vec<f64> {
+    point
+        .iter()
+        .zip(directions.iter())
+        .map(|(v, d)| apply_direction(*v, *d))
+        .collect()
+}
+
+/// Returns the pareto-optimal ordering of a set of points, honoring an
+/// optional per-objective minimize/maximize direction
+///
+/// This is a thin wrapper over [`pareto_sort_rs`]: when `directions` is
+/// given, every point is transformed (negating minimized coordinates) before
+/// sorting, and the survivors are transformed back, since negation is its
+/// own inverse.
+///
 /// # Arguments
 ///
 /// * `points` - A vector of vectors of f64. Each vector will be a point in the multi-objective optimization space.
+/// * `directions` - An optional slice of `1` (maximize) / `-1` (minimize), one
+///   per objective. Defaults to all-maximize when `None`, so existing callers
+///   are unaffected.
 ///
 /// # Example
 ///
@@ -121,7 +247,7 @@ fn domination_order(a: &[f64], b: &[f64]) -> Ordering {
 ///    vec![1.0, 3.0, 4.0],
 ///    vec![1.0, 3.0, 5.0]
 /// ];
-/// let result = pareto_sort_rs(points);
+/// let result = pareto_sort_with_directions_rs(&points, None);
 /// let expected = vec![
 ///   vec![1.0, 3.0, 5.0],
 ///   vec![1.0, 3.0, 4.0],
@@ -147,37 +273,508 @@ fn domination_order(a: &[f64], b: &[f64]) -> Ordering {
 ///
 /// This function is very fast and can be used in performance critical applications
 ///
-pub fn pareto_sort_rs(points: &[&[f64]]) -> Vec<Vec<f64>> {
-    let mut pareto_front: Vec<Vec<f64>> = Vec::new();
-    for point in points.iter() {
-        let mut dominated = false;
-        pareto_front.retain(|p| match domination_order(*p, *point) {
-            Ordering::Less => {
-                dominated = true;
-                false
+pub fn pareto_sort_with_directions_rs(
+    points: &[&[f64]],
+    directions: Option<&[i8]>,
+) -> Vec<Vec<f64>> {
+    match directions {
+        None => pareto_sort_rs(points).iter().map(|p| p.to_vec()).collect(),
+        Some(dirs) => {
+            let transformed: Vec<Vec<f64>> = points
+                .iter()
+                .map(|p| transform_for_directions(p, dirs))
+                .collect();
+            let refs: Vec<&[f64]> = transformed.iter().map(Vec::as_slice).collect();
+            pareto_sort_rs(&refs)
+                .iter()
+                .map(|p| transform_for_directions(p, dirs))
+                .collect()
+        }
+    }
+}
+
+/// Partitions a set of solutions into successive Pareto fronts
+///
+/// Implements the NSGA-II fast non-dominated sort: for every solution `p` this
+/// computes a domination count `n_p` (how many solutions dominate `p`) and the
+/// set `S_p` of solutions `p` dominates, by comparing each pair once with
+/// [`DominanceOrd::dominance_cmp`]. Every `p` with `n_p == 0` forms front 0.
+/// Then, for each `p` in the current front, `n_q` is decremented for every
+/// `q` in `S_p`, and any `q` that reaches zero joins the next front. This
+/// repeats until every solution has been placed in a front.
+///
+/// Unlike `pareto_sort_rs`, which returns only the non-dominated set and
+/// discards the rest, this returns every front so the full ranking is
+/// available, which is what multi-objective optimizers typically need.
+///
+/// Generic over any `T: DominanceOrd`, so callers are not limited to bare
+/// `&[f64]` points - see [`DominanceOrd`]. For `&[f64]` points that need
+/// per-objective minimize/maximize directions, use
+/// [`non_dominated_fronts_with_directions_rs`] instead.
+///
+/// # Arguments
+///
+/// * `points` - A slice of solutions to rank
+///
+/// # Example
+///
+/// ```rust
+/// let points = vec![
+///     vec![1.0, 1.0],
+///     vec![2.0, 2.0],
+///     vec![0.5, 0.5],
+/// ];
+/// let refs: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+/// let result = non_dominated_fronts_rs(&refs);
+/// assert_eq!(result[0], vec![refs[1]]);
+/// ```
+///
+/// # Returns
+///
+/// A vector of fronts, front 0 being the Pareto-optimal set, front 1 the
+/// points that become optimal once front 0 is removed, and so on
+///
+/// # Panics
+///
+/// This function will panic if the length of any of the points are not equal
+///
+/// # Performance
+///
+/// This runs in `O(K * N^2)` time, where `N` is the number of points and `K`
+/// is the number of fronts produced
+///
+pub fn non_dominated_fronts_rs<T: DominanceOrd + Clone>(points: &[T]) -> Vec<Vec<T>> {
+    let n = points.len();
+    let mut domination_count = vec![0usize; n];
+    let mut dominated_sets: Vec<Vec<usize>> = vec![Vec::new(); n];
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            match points[i].dominance_cmp(&points[j]) {
+                Ordering::Greater => {
+                    dominated_sets[i].push(j);
+                    domination_count[j] += 1;
+                }
+                Ordering::Less => {
+                    dominated_sets[j].push(i);
+                    domination_count[i] += 1;
+                }
+                Ordering::Equal => {}
             }
-            Ordering::Greater => true,
-            Ordering::Equal => {
-                dominated = true;
-                false
+        }
+    }
+
+    let mut fronts: Vec<Vec<T>> = Vec::new();
+    let mut current_front: Vec<usize> = (0..n).filter(|&i| domination_count[i] == 0).collect();
+
+    while !current_front.is_empty() {
+        let mut next_front: Vec<usize> = Vec::new();
+        for &p in &current_front {
+            for &q in &dominated_sets[p] {
+                domination_count[q] -= 1;
+                if domination_count[q] == 0 {
+                    next_front.push(q);
+                }
             }
-        });
-        if !dominated {
-            pareto_front.push(point.to_vec());
+        }
+        fronts.push(current_front.iter().map(|&i| points[i].clone()).collect());
+        current_front = next_front;
+    }
+
+    fronts
+}
+
+/// Partitions a set of points into successive Pareto fronts, honoring an
+/// optional per-objective minimize/maximize direction
+///
+/// This is a thin wrapper over [`non_dominated_fronts_rs`]; see
+/// [`pareto_sort_with_directions_rs`] for how the direction transform works.
+///
+/// # Arguments
+///
+/// * `points` - A slice of points, each a slice of f64
+/// * `directions` - An optional slice of `1` (maximize) / `-1` (minimize), one
+///   per objective. Defaults to all-maximize when `None`.
+///
+/// # Example
+///
+/// ```rust
+/// let points = vec![
+///     vec![1.0, 1.0],
+///     vec![2.0, 2.0],
+///     vec![0.5, 0.5],
+/// ];
+/// let refs: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+/// let result = non_dominated_fronts_with_directions_rs(&refs, None);
+/// assert_eq!(result[0], vec![vec![2.0, 2.0]]);
+/// ```
+///
+/// # Returns
+///
+/// A vector of fronts, front 0 being the Pareto-optimal set, front 1 the
+/// points that become optimal once front 0 is removed, and so on
+///
+/// # Panics
+///
+/// This function will panic if the length of any of the points are not equal
+///
+/// # Performance
+///
+/// This runs in `O(K * N^2)` time, where `N` is the number of points and `K`
+/// is the number of fronts produced
+///
+pub fn non_dominated_fronts_with_directions_rs(
+    points: &[&[f64]],
+    directions: Option<&[i8]>,
+) -> Vec<Vec<Vec<f64>>> {
+    match directions {
+        None => non_dominated_fronts_rs(points)
+            .iter()
+            .map(|front| front.iter().map(|p| p.to_vec()).collect())
+            .collect(),
+        Some(dirs) => {
+            let transformed: Vec<Vec<f64>> = points
+                .iter()
+                .map(|p| transform_for_directions(p, dirs))
+                .collect();
+            let refs: Vec<&[f64]> = transformed.iter().map(Vec::as_slice).collect();
+            non_dominated_fronts_rs(&refs)
+                .iter()
+                .map(|front| {
+                    front
+                        .iter()
+                        .map(|p| transform_for_directions(p, dirs))
+                        .collect()
+                })
+                .collect()
         }
     }
-    pareto_front
+}
+
+/// Computes the NSGA-II crowding distance of every point in a single front
+///
+/// All distances start at 0. For each objective `m`, the front is sorted by
+/// that objective; the two boundary points receive `f64::INFINITY` so they
+/// are always kept, and each interior point `i` accumulates
+/// `(value[i + 1] - value[i - 1]) / (max_m - min_m)` (the objective is
+/// skipped if `max_m == min_m`, to avoid dividing by zero). The distances
+/// for every objective are summed, giving a measure of how isolated each
+/// point is from its neighbors - larger is more isolated, and therefore more
+/// valuable to keep for diversity.
+///
+/// # Arguments
+///
+/// * `front` - A single Pareto front, as a slice of points
+///
+/// # Example
+///
+/// ```rust
+/// let front = vec![vec![1.0, 5.0], vec![2.0, 3.0], vec![3.0, 1.0]];
+/// let refs: Vec<&[f64]> = front.iter().map(|p| p.as_slice()).collect();
+/// let result = crowding_distance_rs(&refs);
+/// assert_eq!(result[0], f64::INFINITY);
+/// assert_eq!(result[2], f64::INFINITY);
+/// ```
+///
+/// # Returns
+///
+/// A vector of distances, one per point, in the same order as `front`
+///
+/// # Panics
+///
+/// This function will panic if the points in `front` are not all the same length
+///
+pub fn crowding_distance_rs(front: &[&[f64]]) -> Vec<f64> {
+    let n = front.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let n_objectives = front[0].len();
+    let mut distances = vec![0.0_f64; n];
+
+    for objective in 0..n_objectives {
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| front[i][objective].total_cmp(&front[j][objective]));
+
+        let min_value = front[order[0]][objective];
+        let max_value = front[order[n - 1]][objective];
+
+        if max_value == min_value {
+            continue;
+        }
+
+        distances[order[0]] = f64::INFINITY;
+        distances[order[n - 1]] = f64::INFINITY;
+
+        for k in 1..n - 1 {
+            let prev = front[order[k - 1]][objective];
+            let next = front[order[k + 1]][objective];
+            distances[order[k]] += (next - prev) / (max_value - min_value);
+        }
+    }
+
+    distances
+}
+
+/// Keeps the `k` most spread-out points of a front, using the crowding
+/// distance as a diversity measure
+///
+/// # Arguments
+///
+/// * `front` - A single Pareto front, as a slice of points
+/// * `k` - The number of points to keep
+///
+/// # Example
+///
+/// ```rust
+/// let front = vec![vec![1.0, 5.0], vec![2.0, 3.0], vec![3.0, 1.0]];
+/// let refs: Vec<&[f64]> = front.iter().map(|p| p.as_slice()).collect();
+/// let result = truncate_front_rs(&refs, 2);
+/// assert_eq!(result.len(), 2);
+/// ```
+///
+/// # Returns
+///
+/// A vector of the `k` points with the largest crowding distance, sorted
+/// from most to least isolated
+///
+/// # Panics
+///
+/// This function will panic if the points in `front` are not all the same length
+///
+pub fn truncate_front_rs(front: &[&[f64]], k: usize) -> Vec<Vec<f64>> {
+    let distances = crowding_distance_rs(front);
+    let mut order: Vec<usize> = (0..front.len()).collect();
+    order.sort_by(|&i, &j| distances[j].total_cmp(&distances[i]));
+    order
+        .into_iter()
+        .take(k)
+        .map(|i| front[i].to_vec())
+        .collect()
+}
+
+/// Maps a point to its box (grid) index, by flooring each coordinate's
+/// ratio to the corresponding `epsilon`
+fn box_index(point: &[f64], epsilon: &[f64]) -> Vec<i64> {
+    point
+        .iter()
+        .zip(epsilon.iter())
+        .map(|(value, e)| (value / e).floor() as i64)
+        .collect()
+}
+
+/// Euclidean distance from `point` to the ideal (best) corner of its box,
+/// used to pick which point a box keeps when several points fall in it
+fn distance_to_ideal_corner(point: &[f64], box_idx: &[i64], epsilon: &[f64]) -> f64 {
+    box_idx
+        .iter()
+        .zip(epsilon.iter())
+        .zip(point.iter())
+        .map(|((b, e), v)| {
+            let ideal = (*b as f64 + 1.0) * e;
+            (v - ideal).powi(2)
+        })
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Thins a set of points into an epsilon-dominance (box) Pareto archive
+///
+/// Each point is mapped to a box index per objective via
+/// `floor(value_i / epsilon_i)`. Box `a` epsilon-dominates box `b` if `a`'s
+/// box-index vector dominates `b`'s (using [`is_dominated`] on the integer
+/// box coordinates); within the same box, only the point closest to the
+/// box's ideal corner is kept. The result is a thinned front where no two
+/// retained points share a box, giving a tunable-resolution archive that
+/// stays small even for many-objective problems.
+///
+/// # Arguments
+///
+/// * `points` - A slice of points, each a slice of f64
+/// * `epsilon` - The box width per objective
+///
+/// # Example
+///
+/// ```rust
+/// let points = vec![
+///     vec![1.0, 1.0],
+///     vec![1.05, 1.05],
+///     vec![5.0, 5.0],
+/// ];
+/// let refs: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+/// let epsilon = vec![1.0, 1.0];
+/// let result = epsilon_pareto_sort_rs(&refs, &epsilon);
+/// assert_eq!(result.len(), 1);
+/// assert_eq!(result[0], vec![5.0, 5.0]);
+/// ```
+///
+/// # Returns
+///
+/// A vector of the retained points, at most one per occupied box
+///
+/// # Panics
+///
+/// This function will panic if the length of any of the points, or `epsilon`, are not equal
+///
+pub fn epsilon_pareto_sort_rs(points: &[&[f64]], epsilon: &[f64]) -> Vec<Vec<f64>> {
+    use std::collections::BTreeMap;
+
+    let mut best_by_box: BTreeMap<Vec<i64>, Vec<f64>> = BTreeMap::new();
+    for point in points {
+        let idx = box_index(point, epsilon);
+        let candidate_distance = distance_to_ideal_corner(point, &idx, epsilon);
+        match best_by_box.get(&idx) {
+            Some(existing) if distance_to_ideal_corner(existing, &idx, epsilon) <= candidate_distance => {}
+            _ => {
+                best_by_box.insert(idx, point.to_vec());
+            }
+        }
+    }
+
+    let box_indices: Vec<Vec<i64>> = best_by_box.keys().cloned().collect();
+    let box_indices_f64: Vec<Vec<f64>> = box_indices
+        .iter()
+        .map(|b| b.iter().map(|x| *x as f64).collect())
+        .collect();
+    let box_refs: Vec<&[f64]> = box_indices_f64.iter().map(Vec::as_slice).collect();
+
+    box_refs
+        .iter()
+        .enumerate()
+        .filter(|(i, b)| {
+            !box_refs
+                .iter()
+                .enumerate()
+                .any(|(j, other)| j != *i && is_dominated(other, b, None))
+        })
+        .map(|(i, _)| best_by_box[&box_indices[i]].clone())
+        .collect()
+}
+
+/// Assigns each point its non-domination front index, stopping early once
+/// `n_below` points have been ranked
+///
+/// Builds on the same domination-count algorithm as [`non_dominated_fronts_rs`],
+/// but tracks how many points have been placed into fronts and breaks out of
+/// the front-construction loop once that count reaches `n_below`. The count is
+/// checked between fronts, so the last front ranked is always ranked whole and
+/// more than `n_below` points may end up ranked. Points that never get ranked
+/// keep the sentinel `usize::MAX`. This supports
+/// "pick the best N" selection without fully sorting large populations.
+///
+/// # Arguments
+///
+/// * `points` - A slice of points, each a slice of f64
+/// * `n_below` - The number of points to rank before stopping early
+///
+/// # Example
+///
+/// ```rust
+/// let points = vec![vec![1.0, 1.0], vec![2.0, 2.0], vec![0.5, 0.5]];
+/// let refs: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+/// let result = non_domination_rank_rs(&refs, 1);
+/// assert_eq!(result[1], 0);
+/// ```
+///
+/// # Returns
+///
+/// A vector of ranks, one per point, in the same order as `points`. Points
+/// left unranked once `n_below` is reached carry `usize::MAX`.
+///
+/// # Panics
+///
+/// This function will panic if the length of any of the points are not equal
+///
+pub fn non_domination_rank_rs(points: &[&[f64]], n_below: usize) -> Vec<usize> {
+    let n = points.len();
+    let mut rank = vec![usize::MAX; n];
+    let mut domination_count = vec![0usize; n];
+    let mut dominated_sets: Vec<Vec<usize>> = vec![Vec::new(); n];
+
+    for i in 0..n {
+        for j in 0..n {
+            if i == j {
+                continue;
+            }
+            if is_dominated(points[i], points[j], None) {
+                dominated_sets[i].push(j);
+            } else if is_dominated(points[j], points[i], None) {
+                domination_count[i] += 1;
+            }
+        }
+    }
+
+    let mut current_front: Vec<usize> = (0..n).filter(|&i| domination_count[i] == 0).collect();
+    let mut front_index = 0;
+    let mut ranked = 0;
+
+    while !current_front.is_empty() && ranked < n_below {
+        let mut next_front: Vec<usize> = Vec::new();
+        for &p in &current_front {
+            rank[p] = front_index;
+            ranked += 1;
+            for &q in &dominated_sets[p] {
+                domination_count[q] -= 1;
+                if domination_count[q] == 0 {
+                    next_front.push(q);
+                }
+            }
+        }
+        front_index += 1;
+        current_front = next_front;
+    }
+
+    rank
 }
 
 #[cfg(test)]
 
 mod tests {
 
+    #[test]
+    fn test_non_dominated_fronts_first_front_is_pareto_optimal() {
+        let points = vec![
+            vec![1.0, 1.0, 3.0],
+            vec![1.0, 1.0, 4.0],
+            vec![1.0, 2.0, 8.0],
+            vec![1.0, 2.0, 10.0],
+        ];
+        let refs: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+        let result = super::non_dominated_fronts_rs(&refs);
+        assert!(result[0].contains(&refs[3]));
+    }
+
+    #[test]
+    fn test_non_dominated_fronts_covers_every_point() {
+        let points = vec![
+            vec![1.0, 5.0],
+            vec![2.0, 4.0],
+            vec![3.0, 3.0],
+            vec![4.0, 2.0],
+            vec![5.0, 1.0],
+            vec![1.0, 1.0],
+        ];
+        let refs: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+        let result = super::non_dominated_fronts_rs(&refs);
+        let total: usize = result.iter().map(|front| front.len()).sum();
+        assert_eq!(total, points.len());
+    }
+
+    #[test]
+    fn test_non_dominated_fronts_dominated_point_in_later_front() {
+        let points = vec![vec![2.0, 2.0], vec![1.0, 1.0]];
+        let refs: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+        let result = super::non_dominated_fronts_rs(&refs);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0], vec![refs[0]]);
+        assert_eq!(result[1], vec![refs[1]]);
+    }
+
     #[test]
     fn test_is_dominated_eq_false() {
         let a = vec![1.0, 2.0, 3.0];
         let b = vec![1.0, 2.0, 4.0];
-        let result = super::is_dominated(a.as_slice(), b.as_slice());
+        let result = super::is_dominated(a.as_slice(), b.as_slice(), None);
         assert_eq!(result, false);
     }
 
@@ -185,7 +782,7 @@ mod tests {
     fn test_is_dominated_eq_true() {
         let a = vec![1.0, 2.0, 4.0];
         let b = vec![1.0, 2.0, 3.0];
-        let result = super::is_dominated(a.as_slice(), b.as_slice());
+        let result = super::is_dominated(a.as_slice(), b.as_slice(), None);
         assert_eq!(result, true);
     }
 
@@ -193,7 +790,7 @@ mod tests {
     fn test_domination_order_eq_less() {
         let a = vec![1.0, 2.0, 3.0];
         let b = vec![1.0, 2.0, 4.0];
-        let result = super::domination_order(a.as_slice(), b.as_slice());
+        let result = super::domination_order(a.as_slice(), b.as_slice(), None);
         assert_eq!(result, std::cmp::Ordering::Less);
     }
 
@@ -201,7 +798,7 @@ mod tests {
     fn test_domination_order_eq_greater() {
         let a = vec![1.0, 2.0, 4.0];
         let b = vec![1.0, 2.0, 3.0];
-        let result = super::domination_order(a.as_slice(), b.as_slice());
+        let result = super::domination_order(a.as_slice(), b.as_slice(), None);
         assert_eq!(result, std::cmp::Ordering::Greater);
     }
 
@@ -209,7 +806,7 @@ mod tests {
     fn test_domination_order_eq_equal_because_actually_equal() {
         let a = vec![1.0, 2.0, 3.0];
         let b = vec![1.0, 2.0, 3.0];
-        let result = super::domination_order(a.as_slice(), b.as_slice());
+        let result = super::domination_order(a.as_slice(), b.as_slice(), None);
         assert_eq!(result, std::cmp::Ordering::Equal);
     }
 
@@ -217,7 +814,7 @@ mod tests {
     fn test_domination_order_eq_equal_because_no_pareto_optimal_front() {
         let a = vec![1.0, 3.0, 2.0];
         let b = vec![1.0, 2.0, 3.0];
-        let result = super::domination_order(a.as_slice(), b.as_slice());
+        let result = super::domination_order(a.as_slice(), b.as_slice(), None);
         assert_eq!(result, std::cmp::Ordering::Equal);
     }
 
@@ -234,9 +831,146 @@ mod tests {
             vec![1.0, 2.0, 9.0],
             vec![1.0, 2.0, 10.0],
         ];
-        let result = super::pareto_sort_rs(&points);
-        assert_eq!(result.len(), points.len());
+        let refs: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+        let result = super::pareto_sort_rs(&refs);
+        assert_eq!(result.len(), 1);
+
+        assert_eq!(result[0], refs[8]);
+    }
 
-        assert_eq!(result[0], vec![1.0, 2.0, 10.0]);
+    #[test]
+    fn test_is_dominated_respects_minimize_direction() {
+        let a = vec![1.0, 5.0];
+        let b = vec![1.0, 2.0];
+        // second objective is minimized, so b (lower cost) dominates a
+        let directions = vec![1, -1];
+        let result = super::is_dominated(b.as_slice(), a.as_slice(), Some(&directions));
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn test_non_dominated_fronts_with_minimize_direction() {
+        let points = vec![vec![1.0, 5.0], vec![1.0, 2.0]];
+        let refs: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+        let directions = vec![1, -1];
+        let result = super::non_dominated_fronts_with_directions_rs(&refs, Some(&directions));
+        assert_eq!(result[0], vec![vec![1.0, 2.0]]);
+        assert_eq!(result[1], vec![vec![1.0, 5.0]]);
+    }
+
+    #[test]
+    fn test_pareto_sort_with_directions_honors_minimize() {
+        let points = vec![vec![1.0, 5.0], vec![1.0, 2.0]];
+        let refs: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+        let directions = vec![1, -1];
+        let result = super::pareto_sort_with_directions_rs(&refs, Some(&directions));
+        assert_eq!(result, vec![vec![1.0, 2.0]]);
+    }
+
+    #[test]
+    fn test_dominance_ord_matches_domination_order() {
+        use super::DominanceOrd;
+        let a: &[f64] = &[1.0, 2.0, 4.0];
+        let b: &[f64] = &[1.0, 2.0, 3.0];
+        assert_eq!(a.dominance_cmp(&b), super::domination_order(a, b, None));
+    }
+
+    #[test]
+    fn test_crowding_distance_boundary_points_are_infinite() {
+        let front = vec![vec![1.0, 5.0], vec![2.0, 3.0], vec![3.0, 1.0]];
+        let refs: Vec<&[f64]> = front.iter().map(|p| p.as_slice()).collect();
+        let result = super::crowding_distance_rs(&refs);
+        assert_eq!(result[0], f64::INFINITY);
+        assert_eq!(result[2], f64::INFINITY);
+        assert!(result[1].is_finite());
+    }
+
+    #[test]
+    fn test_crowding_distance_skips_constant_objective() {
+        let front = vec![vec![1.0, 5.0], vec![1.0, 3.0], vec![1.0, 1.0]];
+        let refs: Vec<&[f64]> = front.iter().map(|p| p.as_slice()).collect();
+        let result = super::crowding_distance_rs(&refs);
+        assert_eq!(result[0], f64::INFINITY);
+        assert_eq!(result[2], f64::INFINITY);
+        assert_eq!(result[1], 1.0);
+    }
+
+    #[test]
+    fn test_crowding_distance_constant_objective_boundary_does_not_leak() {
+        // The constant first objective's own "boundary" indices (0 and 2, in
+        // input order) differ from the varying second objective's extremes
+        // (indices 1 and 2), so this catches the skip being applied too late.
+        let front = vec![vec![1.0, 3.0], vec![1.0, 5.0], vec![1.0, 1.0]];
+        let refs: Vec<&[f64]> = front.iter().map(|p| p.as_slice()).collect();
+        let result = super::crowding_distance_rs(&refs);
+        assert_eq!(result[0], 1.0);
+        assert_eq!(result[1], f64::INFINITY);
+        assert_eq!(result[2], f64::INFINITY);
+    }
+
+    #[test]
+    fn test_truncate_front_keeps_k_most_spread_out_points() {
+        let front = vec![
+            vec![1.0, 5.0],
+            vec![2.0, 4.0],
+            vec![3.0, 3.0],
+            vec![4.0, 2.0],
+            vec![5.0, 1.0],
+        ];
+        let refs: Vec<&[f64]> = front.iter().map(|p| p.as_slice()).collect();
+        let result = super::truncate_front_rs(&refs, 2);
+        assert_eq!(result.len(), 2);
+        assert!(result.contains(&vec![1.0, 5.0]));
+        assert!(result.contains(&vec![5.0, 1.0]));
+    }
+
+    #[test]
+    fn test_epsilon_pareto_sort_thins_a_cluster_to_one_point() {
+        let points = vec![vec![1.0, 1.0], vec![1.05, 1.05], vec![5.0, 5.0]];
+        let refs: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+        let epsilon = vec![1.0, 1.0];
+        let result = super::epsilon_pareto_sort_rs(&refs, &epsilon);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0], vec![5.0, 5.0]);
+    }
+
+    #[test]
+    fn test_epsilon_pareto_sort_keeps_one_point_per_box() {
+        let points = vec![
+            vec![0.1, 0.9],
+            vec![0.9, 0.1],
+            vec![5.0, 5.0],
+            vec![5.4, 5.4],
+        ];
+        let refs: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+        let epsilon = vec![1.0, 1.0];
+        let result = super::epsilon_pareto_sort_rs(&refs, &epsilon);
+        assert_eq!(result.len(), 1);
+        assert!(result.contains(&vec![5.4, 5.4]) || result.contains(&vec![5.0, 5.0]));
+    }
+
+    #[test]
+    fn test_non_domination_rank_ranks_pareto_front_zero() {
+        let points = vec![vec![1.0, 1.0], vec![2.0, 2.0], vec![0.5, 0.5]];
+        let refs: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+        let result = super::non_domination_rank_rs(&refs, usize::MAX);
+        assert_eq!(result[1], 0);
+        assert_eq!(result[0], 1);
+        assert_eq!(result[2], 2);
+    }
+
+    #[test]
+    fn test_non_domination_rank_stops_early() {
+        let points = vec![
+            vec![1.0, 1.0, 3.0],
+            vec![1.0, 1.0, 4.0],
+            vec![1.0, 2.0, 8.0],
+            vec![1.0, 2.0, 10.0],
+        ];
+        let refs: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+        let result = super::non_domination_rank_rs(&refs, 1);
+        let ranked = result.iter().filter(|&&r| r != usize::MAX).count();
+        assert!(ranked >= 1);
+        assert!(ranked < points.len());
     }
 }
@@ -1,15 +1,62 @@
-use crate::_pareto_sort::pareto_sort_rs;
+use crate::_pareto_sort::{
+    crowding_distance_rs, epsilon_pareto_sort_rs, non_dominated_fronts_with_directions_rs,
+    non_domination_rank_rs, pareto_sort_with_directions_rs, truncate_front_rs,
+};
 use pyo3::prelude::*;
-mod _pareto_sort;
+pub mod _pareto_sort;
+
+/// Re-exported so downstream crates can implement [`DominanceOrd`] for their
+/// own solution types and rank them with [`pareto_sort_rs`] without reaching
+/// into the `_pareto_sort` module directly.
+pub use crate::_pareto_sort::{pareto_sort_rs, DominanceOrd};
+
+#[pyfunction(signature = (points, directions=None))]
+fn pareto_sort(points: Vec<Vec<f64>>, directions: Option<Vec<i8>>) -> Vec<Vec<f64>> {
+    let refs: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+    pareto_sort_with_directions_rs(&refs, directions.as_deref())
+}
+
+#[pyfunction(signature = (points, directions=None))]
+fn non_dominated_fronts(
+    points: Vec<Vec<f64>>,
+    directions: Option<Vec<i8>>,
+) -> Vec<Vec<Vec<f64>>> {
+    let refs: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+    non_dominated_fronts_with_directions_rs(&refs, directions.as_deref())
+}
+
+#[pyfunction]
+fn crowding_distance(front: Vec<Vec<f64>>) -> Vec<f64> {
+    let refs: Vec<&[f64]> = front.iter().map(|p| p.as_slice()).collect();
+    crowding_distance_rs(&refs)
+}
+
+#[pyfunction]
+fn truncate_front(front: Vec<Vec<f64>>, k: usize) -> Vec<Vec<f64>> {
+    let refs: Vec<&[f64]> = front.iter().map(|p| p.as_slice()).collect();
+    truncate_front_rs(&refs, k)
+}
+
+#[pyfunction]
+fn epsilon_pareto_sort(points: Vec<Vec<f64>>, epsilon: Vec<f64>) -> Vec<Vec<f64>> {
+    let refs: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+    epsilon_pareto_sort_rs(&refs, &epsilon)
+}
 
 #[pyfunction]
-fn pareto_sort(points: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
-    pareto_sort_rs(&points)
+fn non_domination_rank(points: Vec<Vec<f64>>, n_below: usize) -> Vec<usize> {
+    let refs: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+    non_domination_rank_rs(&refs, n_below)
 }
 
 #[pymodule]
 fn predictables_rs(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(pareto_sort, m)?)?;
+    m.add_function(wrap_pyfunction!(non_dominated_fronts, m)?)?;
+    m.add_function(wrap_pyfunction!(crowding_distance, m)?)?;
+    m.add_function(wrap_pyfunction!(truncate_front, m)?)?;
+    m.add_function(wrap_pyfunction!(epsilon_pareto_sort, m)?)?;
+    m.add_function(wrap_pyfunction!(non_domination_rank, m)?)?;
     Ok(())
 }
 
@@ -48,7 +95,10 @@ mod tests {
             vec![1.0, 2.0, 28.0],
             vec![1.0, 2.0, 29.0],
         ];
-        let result = super::pareto_sort_rs(&points);
+        let result = super::pareto_sort_with_directions_rs(
+            &points.iter().map(|p| p.as_slice()).collect::<Vec<&[f64]>>(),
+            None,
+        );
         assert_eq!(result.len(), 1);
 
         assert_eq!(result[0], vec![1.0, 2.0, 29.0]);